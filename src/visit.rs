@@ -1,85 +1,299 @@
 #![allow(dead_code)]
 
+use std::collections::HashSet;
+use std::ops::ControlFlow;
+
 use rustdoc_types::{
-    AssocItemConstraint, AssocItemConstraintKind, DynTrait, Enum, Function, FunctionPointer,
+    AssocItemConstraint, AssocItemConstraintKind, Crate, DynTrait, Enum, Function, FunctionPointer,
     FunctionSignature, GenericArg, GenericArgs, GenericBound, GenericParamDef, GenericParamDefKind,
-    Generics, Impl, Item, ItemEnum, Path, PolyTrait, Static, Struct, StructKind, Term, Trait,
-    TraitAlias, Type, TypeAlias, Union, Use, WherePredicate,
+    Generics, Id, Impl, Item, ItemEnum, Path, PolyTrait, Static, Struct, StructKind, Term, Trait,
+    TraitAlias, Type, TypeAlias, Union, Use, Variant, VariantKind, Visibility, WherePredicate,
 };
 
+/// Propagate a `ControlFlow::Break` out of the current function, otherwise
+/// keep going. The visitor equivalent of the `?` operator for `Result`.
+macro_rules! try_visit {
+    ($e:expr) => {
+        match $e {
+            ControlFlow::Continue(()) => {}
+            b @ ControlFlow::Break(_) => return b,
+        }
+    };
+}
+
+/// Describes *why* a [`Path`] is being visited, i.e. the syntactic position
+/// of the type or trait reference it belongs to.
+///
+/// This is threaded down from the item/type that owns the path so that
+/// [`Visitor::visit_path`] can report not just *that* a path was found, but
+/// *where* it was found (e.g. "in the return type of `fn baz`" versus "as a
+/// supertrait bound").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefContext {
+    /// A function (or method) parameter type.
+    FnInput,
+    /// A function (or method) return type.
+    FnOutput,
+    /// The type of a struct field.
+    StructField,
+    /// A supertrait bound on a trait declaration.
+    Supertrait,
+    /// A trait bound on a generic parameter.
+    TraitBound,
+    /// A bound (or bounded type) in a `where` clause.
+    WhereClause,
+    /// A trait referenced by a `dyn Trait` object.
+    DynTraitObject,
+    /// A trait referenced by an `impl Trait` type.
+    ImplTrait,
+    /// The default type of a generic parameter or associated type, the type
+    /// of a const generic parameter, or the type an `impl` assigns to an
+    /// associated type.
+    GenericDefault,
+    /// A bound on an associated type (`type Item: Bound`).
+    AssocTypeBound,
+    /// The target type of a `type Alias = ...` declaration.
+    TypeAliasTarget,
+    /// The type of a `static` item.
+    StaticType,
+    /// The type of a `const` item or associated constant.
+    ConstType,
+    /// The `Self` type of an `impl` block.
+    ImplSelfType,
+    /// The trait named in `impl Trait for Type`.
+    ImplTraitRef,
+}
+
+/// Visits the nodes of a rustdoc JSON API surface.
+///
+/// Every method has a default implementation that calls the matching
+/// `walk_*` free function, which performs the actual recursive descent.
+/// Override a method to hook into that node; call the corresponding
+/// `walk_*` function from your override to keep descending into its
+/// children, or omit the call to prune that subtree. Return
+/// [`ControlFlow::Break`] from any method to stop the walk entirely.
 #[allow(unused_variables)]
 pub trait Visitor {
     #[inline]
-    fn visit_path(&mut self, path: &Path) {}
+    fn visit_item(&mut self, item: &Item) -> ControlFlow<()> {
+        walk_item(self, item)
+    }
+
+    #[inline]
+    fn visit_path(&mut self, path: &Path, cx: RefContext) -> ControlFlow<()> {
+        walk_path(self, path, cx)
+    }
+
+    #[inline]
+    fn visit_use(&mut self, use_: &Use) -> ControlFlow<()> {
+        walk_use(self, use_)
+    }
+
+    #[inline]
+    fn visit_type(&mut self, type_: &Type, cx: RefContext) -> ControlFlow<()> {
+        walk_type(self, type_, cx)
+    }
+
+    #[inline]
+    fn visit_static(&mut self, static_: &Static) -> ControlFlow<()> {
+        walk_static(self, static_)
+    }
+
+    #[inline]
+    fn visit_trait_alias(&mut self, trait_alias: &TraitAlias) -> ControlFlow<()> {
+        walk_trait_alias(self, trait_alias)
+    }
+
+    #[inline]
+    fn visit_trait(&mut self, trait_: &Trait) -> ControlFlow<()> {
+        walk_trait(self, trait_)
+    }
+
+    #[inline]
+    fn visit_enum(&mut self, enum_: &Enum) -> ControlFlow<()> {
+        walk_enum(self, enum_)
+    }
+
+    #[inline]
+    fn visit_union(&mut self, union_: &Union) -> ControlFlow<()> {
+        walk_union(self, union_)
+    }
+
+    #[inline]
+    fn visit_impl(&mut self, impl_: &Impl) -> ControlFlow<()> {
+        walk_impl(self, impl_)
+    }
+
+    #[inline]
+    fn visit_type_alias(&mut self, type_alias: &TypeAlias) -> ControlFlow<()> {
+        walk_type_alias(self, type_alias)
+    }
+
+    #[inline]
+    fn visit_struct(&mut self, struct_: &Struct) -> ControlFlow<()> {
+        walk_struct(self, struct_)
+    }
+
+    #[inline]
+    fn visit_struct_kind(&mut self, kind: &StructKind) -> ControlFlow<()> {
+        walk_struct_kind(self, kind)
+    }
+
+    #[inline]
+    fn visit_function(&mut self, fun: &Function) -> ControlFlow<()> {
+        walk_function(self, fun)
+    }
 
     #[inline]
-    fn visit_use(&mut self, use_: &Use) {}
+    fn visit_fn_sig(&mut self, sig: &FunctionSignature) -> ControlFlow<()> {
+        walk_fn_sig(self, sig)
+    }
+
+    #[inline]
+    fn visit_generics(&mut self, generics: &Generics) -> ControlFlow<()> {
+        walk_generics(self, generics)
+    }
+
+    #[inline]
+    fn visit_generic_param_def(&mut self, param: &GenericParamDef) -> ControlFlow<()> {
+        walk_generic_param_def(self, param)
+    }
+
+    #[inline]
+    fn visit_generic_param_def_kind(&mut self, kind: &GenericParamDefKind) -> ControlFlow<()> {
+        walk_generic_param_def_kind(self, kind)
+    }
+
+    #[inline]
+    fn visit_where_predicate(&mut self, where_predicate: &WherePredicate) -> ControlFlow<()> {
+        walk_where_predicate(self, where_predicate)
+    }
+
+    #[inline]
+    fn visit_generic_bound(&mut self, bound: &GenericBound, cx: RefContext) -> ControlFlow<()> {
+        walk_generic_bound(self, bound, cx)
+    }
+
+    #[inline]
+    fn visit_term(&mut self, term: &Term, cx: RefContext) -> ControlFlow<()> {
+        walk_term(self, term, cx)
+    }
+
+    #[inline]
+    fn visit_generic_args(&mut self, args: &GenericArgs, cx: RefContext) -> ControlFlow<()> {
+        walk_generic_args(self, args, cx)
+    }
+
+    #[inline]
+    fn visit_generic_arg(&mut self, arg: &GenericArg, cx: RefContext) -> ControlFlow<()> {
+        walk_generic_arg(self, arg, cx)
+    }
+
+    #[inline]
+    fn visit_assoc_item_constraint(
+        &mut self,
+        constraint: &AssocItemConstraint,
+        cx: RefContext,
+    ) -> ControlFlow<()> {
+        walk_assoc_item_constraint(self, constraint, cx)
+    }
+
+    #[inline]
+    fn visit_assoc_item_constraint_kind(
+        &mut self,
+        kind: &AssocItemConstraintKind,
+        cx: RefContext,
+    ) -> ControlFlow<()> {
+        walk_assoc_item_constraint_kind(self, kind, cx)
+    }
+
+    #[inline]
+    fn visit_function_pointer(&mut self, fn_pointer: &FunctionPointer) -> ControlFlow<()> {
+        walk_function_pointer(self, fn_pointer)
+    }
+
+    #[inline]
+    fn visit_dyn_trait(&mut self, dyn_trait: &DynTrait) -> ControlFlow<()> {
+        walk_dyn_trait(self, dyn_trait)
+    }
+
+    #[inline]
+    fn visit_poly_trait(&mut self, trait_: &PolyTrait) -> ControlFlow<()> {
+        walk_poly_trait(self, trait_)
+    }
 }
 
-pub fn visit_item(item: &Item, v: &mut impl Visitor) {
+pub fn walk_item<V: Visitor + ?Sized>(v: &mut V, item: &Item) -> ControlFlow<()> {
     match &item.inner {
-        ItemEnum::Function(fun) => visit_function(fun, v),
-        ItemEnum::Struct(struct_) => visit_struct(struct_, v),
-        ItemEnum::StructField(field_type) => visit_type(field_type, v),
+        ItemEnum::Function(fun) => v.visit_function(fun),
+        ItemEnum::Struct(struct_) => v.visit_struct(struct_),
+        ItemEnum::StructField(field_type) => v.visit_type(field_type, RefContext::StructField),
         ItemEnum::AssocType {
             generics,
             bounds,
             type_,
         } => {
-            visit_generics(generics, v);
+            try_visit!(v.visit_generics(generics));
             for bound in bounds {
-                visit_generic_bound(bound, v);
+                try_visit!(v.visit_generic_bound(bound, RefContext::AssocTypeBound));
             }
             if let Some(type_) = type_ {
-                visit_type(type_, v);
+                try_visit!(v.visit_type(type_, RefContext::GenericDefault));
             }
+            ControlFlow::Continue(())
         }
-        ItemEnum::AssocConst { type_, value: _ } => {
-            visit_type(type_, v);
-        }
-        ItemEnum::Impl(impl_) => visit_impl(impl_, v),
-        ItemEnum::TypeAlias(type_alias) => visit_type_alias(type_alias, v),
-        ItemEnum::Union(union_) => visit_union(union_, v),
-        ItemEnum::Enum(enum_) => visit_enum(enum_, v),
-
-        ItemEnum::Trait(trait_) => visit_trait(trait_, v),
-        ItemEnum::TraitAlias(trait_alias) => visit_trait_alias(trait_alias, v),
-        ItemEnum::Constant { type_, const_: _ } => visit_type(type_, v),
-        ItemEnum::Static(static_) => visit_static(static_, v),
-        ItemEnum::Use(use_) => {
-            v.visit_use(use_);
-        }
+        ItemEnum::AssocConst { type_, value: _ } => v.visit_type(type_, RefContext::ConstType),
+        ItemEnum::Impl(impl_) => v.visit_impl(impl_),
+        ItemEnum::TypeAlias(type_alias) => v.visit_type_alias(type_alias),
+        ItemEnum::Union(union_) => v.visit_union(union_),
+        ItemEnum::Enum(enum_) => v.visit_enum(enum_),
+
+        ItemEnum::Trait(trait_) => v.visit_trait(trait_),
+        ItemEnum::TraitAlias(trait_alias) => v.visit_trait_alias(trait_alias),
+        ItemEnum::Constant { type_, const_: _ } => v.visit_type(type_, RefContext::ConstType),
+        ItemEnum::Static(static_) => v.visit_static(static_),
+        ItemEnum::Use(use_) => v.visit_use(use_),
 
         // ignore these because they don't contain anything of interest
-        ItemEnum::Module(_) => {}
-        ItemEnum::Variant(_) => {}
-        ItemEnum::ExternCrate { .. } => {}
+        ItemEnum::Module(_) => ControlFlow::Continue(()),
+        ItemEnum::Variant(_) => ControlFlow::Continue(()),
+        ItemEnum::ExternCrate { .. } => ControlFlow::Continue(()),
         ItemEnum::ExternType => todo!(),
-        ItemEnum::Primitive(_) => {}
-        ItemEnum::ProcMacro(_) => {}
-        ItemEnum::Macro(_) => {}
+        ItemEnum::Primitive(_) => ControlFlow::Continue(()),
+        ItemEnum::ProcMacro(_) => ControlFlow::Continue(()),
+        ItemEnum::Macro(_) => ControlFlow::Continue(()),
     }
 }
 
-fn visit_static(static_: &Static, v: &mut impl Visitor) {
+/// A `Use` holds no types of its own; following it to the item it re-exports
+/// needs the crate index, see [`IndexWalker`].
+pub fn walk_use<V: Visitor + ?Sized>(_v: &mut V, _use_: &Use) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+}
+
+pub fn walk_static<V: Visitor + ?Sized>(v: &mut V, static_: &Static) -> ControlFlow<()> {
     let Static {
         is_mutable: _,
         is_unsafe: _,
         type_,
         expr: _,
     } = static_;
-    visit_type(type_, v);
+    v.visit_type(type_, RefContext::StaticType)
 }
 
-fn visit_trait_alias(trait_alias: &TraitAlias, v: &mut impl Visitor) {
+pub fn walk_trait_alias<V: Visitor + ?Sized>(
+    v: &mut V,
+    trait_alias: &TraitAlias,
+) -> ControlFlow<()> {
     let TraitAlias { generics, params } = trait_alias;
-    visit_generics(generics, v);
+    try_visit!(v.visit_generics(generics));
     for param in params {
-        visit_generic_bound(param, v);
+        try_visit!(v.visit_generic_bound(param, RefContext::Supertrait));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_trait(trait_: &Trait, v: &mut impl Visitor) {
+pub fn walk_trait<V: Visitor + ?Sized>(v: &mut V, trait_: &Trait) -> ControlFlow<()> {
     let Trait {
         is_auto: _,
         is_unsafe: _,
@@ -89,33 +303,34 @@ fn visit_trait(trait_: &Trait, v: &mut impl Visitor) {
         bounds,
         implementations: _,
     } = trait_;
-    visit_generics(generics, v);
+    try_visit!(v.visit_generics(generics));
     for bound in bounds {
-        visit_generic_bound(bound, v);
+        try_visit!(v.visit_generic_bound(bound, RefContext::Supertrait));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_enum(enum_: &Enum, v: &mut impl Visitor) {
+pub fn walk_enum<V: Visitor + ?Sized>(v: &mut V, enum_: &Enum) -> ControlFlow<()> {
     let Enum {
         generics,
         has_stripped_variants: _,
         variants: _,
         impls: _,
     } = enum_;
-    visit_generics(generics, v);
+    v.visit_generics(generics)
 }
 
-fn visit_union(union: &Union, v: &mut impl Visitor) {
+pub fn walk_union<V: Visitor + ?Sized>(v: &mut V, union: &Union) -> ControlFlow<()> {
     let Union {
         generics,
         has_stripped_fields: _,
         fields: _,
         impls: _,
     } = union;
-    visit_generics(generics, v);
+    v.visit_generics(generics)
 }
 
-fn visit_impl(impl_: &Impl, v: &mut impl Visitor) {
+pub fn walk_impl<V: Visitor + ?Sized>(v: &mut V, impl_: &Impl) -> ControlFlow<()> {
     let Impl {
         is_unsafe: _,
         is_negative: _,
@@ -129,32 +344,32 @@ fn visit_impl(impl_: &Impl, v: &mut impl Visitor) {
     } = impl_;
     // blanket impls in other crates that happen to match one of our types shouldn't count
     if blanket_impl.is_some() {
-        return;
+        return ControlFlow::Continue(());
     }
-    visit_generics(generics, v);
+    try_visit!(v.visit_generics(generics));
     if let Some(trait_) = trait_ {
-        visit_path(trait_, v);
+        try_visit!(v.visit_path(trait_, RefContext::ImplTraitRef));
     }
-    visit_type(for_, v);
+    v.visit_type(for_, RefContext::ImplSelfType)
 }
 
-fn visit_type_alias(type_alias: &TypeAlias, v: &mut impl Visitor) {
+pub fn walk_type_alias<V: Visitor + ?Sized>(v: &mut V, type_alias: &TypeAlias) -> ControlFlow<()> {
     let TypeAlias { type_, generics } = type_alias;
-    visit_type(type_, v);
-    visit_generics(generics, v);
+    try_visit!(v.visit_type(type_, RefContext::TypeAliasTarget));
+    v.visit_generics(generics)
 }
 
-fn visit_struct(struct_: &Struct, v: &mut impl Visitor) {
+pub fn walk_struct<V: Visitor + ?Sized>(v: &mut V, struct_: &Struct) -> ControlFlow<()> {
     let Struct {
         kind,
         generics,
         impls: _,
     } = struct_;
-    visit_struct_kind(kind, v);
-    visit_generics(generics, v);
+    try_visit!(v.visit_struct_kind(kind));
+    v.visit_generics(generics)
 }
 
-fn visit_struct_kind(kind: &StructKind, _v: &mut impl Visitor) {
+pub fn walk_struct_kind<V: Visitor + ?Sized>(_v: &mut V, kind: &StructKind) -> ControlFlow<()> {
     match kind {
         StructKind::Unit => {}
         StructKind::Tuple(_) => {}
@@ -163,76 +378,89 @@ fn visit_struct_kind(kind: &StructKind, _v: &mut impl Visitor) {
             has_stripped_fields: _,
         } => {}
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_function(fun: &Function, v: &mut impl Visitor) {
+pub fn walk_function<V: Visitor + ?Sized>(v: &mut V, fun: &Function) -> ControlFlow<()> {
     let Function {
         sig,
         generics,
         header: _,
         has_body: _,
     } = fun;
-    visit_fn_sig(sig, v);
-    visit_generics(generics, v);
+    try_visit!(v.visit_fn_sig(sig));
+    v.visit_generics(generics)
 }
 
-fn visit_fn_sig(decl: &FunctionSignature, v: &mut impl Visitor) {
+pub fn walk_fn_sig<V: Visitor + ?Sized>(v: &mut V, decl: &FunctionSignature) -> ControlFlow<()> {
     let FunctionSignature {
         is_c_variadic: _,
         inputs,
         output,
     } = decl;
     for (_, ty) in inputs {
-        visit_type(ty, v);
+        try_visit!(v.visit_type(ty, RefContext::FnInput));
     }
     if let Some(output) = output {
-        visit_type(output, v);
+        try_visit!(v.visit_type(output, RefContext::FnOutput));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_generics(generics: &Generics, v: &mut impl Visitor) {
+pub fn walk_generics<V: Visitor + ?Sized>(v: &mut V, generics: &Generics) -> ControlFlow<()> {
     let Generics {
         params,
         where_predicates,
     } = generics;
     for param in params {
-        visit_generic_param_def(param, v);
+        try_visit!(v.visit_generic_param_def(param));
     }
     for where_predicate in where_predicates {
-        visit_where_predicate(where_predicate, v);
+        try_visit!(v.visit_where_predicate(where_predicate));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_generic_param_def(param: &GenericParamDef, v: &mut impl Visitor) {
+pub fn walk_generic_param_def<V: Visitor + ?Sized>(
+    v: &mut V,
+    param: &GenericParamDef,
+) -> ControlFlow<()> {
     let GenericParamDef { name: _, kind } = param;
-    visit_generic_param_def_kind(kind, v);
+    v.visit_generic_param_def_kind(kind)
 }
 
-fn visit_where_predicate(where_predicate: &WherePredicate, v: &mut impl Visitor) {
+pub fn walk_where_predicate<V: Visitor + ?Sized>(
+    v: &mut V,
+    where_predicate: &WherePredicate,
+) -> ControlFlow<()> {
     match where_predicate {
         WherePredicate::BoundPredicate {
             type_,
             bounds,
             generic_params,
         } => {
-            visit_type(type_, v);
+            try_visit!(v.visit_type(type_, RefContext::WhereClause));
             for bound in bounds {
-                visit_generic_bound(bound, v);
+                try_visit!(v.visit_generic_bound(bound, RefContext::WhereClause));
             }
             for generic_param in generic_params {
-                visit_generic_param_def(generic_param, v);
+                try_visit!(v.visit_generic_param_def(generic_param));
             }
         }
         WherePredicate::EqPredicate { lhs, rhs } => {
-            visit_type(lhs, v);
-            visit_term(rhs, v);
+            try_visit!(v.visit_type(lhs, RefContext::WhereClause));
+            try_visit!(v.visit_term(rhs, RefContext::WhereClause));
         }
         // lifetime predicates can only have outlives bounds, ignore
         WherePredicate::LifetimePredicate { .. } => {}
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_generic_param_def_kind(kind: &GenericParamDefKind, v: &mut impl Visitor) {
+pub fn walk_generic_param_def_kind<V: Visitor + ?Sized>(
+    v: &mut V,
+    kind: &GenericParamDefKind,
+) -> ControlFlow<()> {
     match kind {
         GenericParamDefKind::Lifetime { outlives: _ } => {}
         GenericParamDefKind::Type {
@@ -241,186 +469,769 @@ fn visit_generic_param_def_kind(kind: &GenericParamDefKind, v: &mut impl Visitor
             default,
         } => {
             for bound in bounds {
-                visit_generic_bound(bound, v);
+                try_visit!(v.visit_generic_bound(bound, RefContext::TraitBound));
             }
             if let Some(default) = default {
-                visit_type(default, v);
+                try_visit!(v.visit_type(default, RefContext::GenericDefault));
             }
         }
         GenericParamDefKind::Const { type_, default: _ } => {
-            visit_type(type_, v);
+            try_visit!(v.visit_type(type_, RefContext::GenericDefault));
         }
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_generic_bound(bound: &GenericBound, v: &mut impl Visitor) {
+pub fn walk_generic_bound<V: Visitor + ?Sized>(
+    v: &mut V,
+    bound: &GenericBound,
+    cx: RefContext,
+) -> ControlFlow<()> {
     match bound {
         GenericBound::TraitBound {
             trait_,
             generic_params,
             modifier: _,
         } => {
-            visit_path(trait_, v);
+            try_visit!(v.visit_path(trait_, cx));
             for param in generic_params {
-                visit_generic_param_def(param, v);
+                try_visit!(v.visit_generic_param_def(param));
             }
         }
         GenericBound::Outlives(_) => {}
         GenericBound::Use(_) => {}
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_term(term: &Term, v: &mut impl Visitor) {
+pub fn walk_term<V: Visitor + ?Sized>(v: &mut V, term: &Term, cx: RefContext) -> ControlFlow<()> {
     match term {
-        Term::Type(type_) => visit_type(type_, v),
-        Term::Constant(_) => {}
+        Term::Type(type_) => v.visit_type(type_, cx),
+        Term::Constant(_) => ControlFlow::Continue(()),
     }
 }
 
-fn visit_path(path: &Path, v: &mut impl Visitor) {
-    v.visit_path(path);
+pub fn walk_path<V: Visitor + ?Sized>(v: &mut V, path: &Path, cx: RefContext) -> ControlFlow<()> {
     let Path {
         path: _,
         id: _,
         args,
     } = path;
     if let Some(args) = args {
-        visit_generic_args(args, v);
+        v.visit_generic_args(args, cx)
+    } else {
+        ControlFlow::Continue(())
     }
 }
 
-fn visit_generic_args(args: &GenericArgs, v: &mut impl Visitor) {
+pub fn walk_generic_args<V: Visitor + ?Sized>(
+    v: &mut V,
+    args: &GenericArgs,
+    cx: RefContext,
+) -> ControlFlow<()> {
     match args {
         GenericArgs::AngleBracketed { args, constraints } => {
             for arg in args {
-                visit_generic_arg(arg, v);
+                try_visit!(v.visit_generic_arg(arg, cx));
             }
             for constraint in constraints {
-                visit_assoc_item_constraint(constraint, v);
+                try_visit!(v.visit_assoc_item_constraint(constraint, cx));
             }
         }
         GenericArgs::Parenthesized { inputs, output } => {
             for type_ in inputs {
-                visit_type(type_, v);
+                try_visit!(v.visit_type(type_, cx));
             }
             if let Some(type_) = output {
-                visit_type(type_, v);
+                try_visit!(v.visit_type(type_, cx));
             }
         }
         GenericArgs::ReturnTypeNotation => {}
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_assoc_item_constraint(binding: &AssocItemConstraint, v: &mut impl Visitor) {
+pub fn walk_assoc_item_constraint<V: Visitor + ?Sized>(
+    v: &mut V,
+    binding: &AssocItemConstraint,
+    cx: RefContext,
+) -> ControlFlow<()> {
     let AssocItemConstraint {
         name: _,
         args,
         binding,
     } = binding;
     if let Some(args) = args {
-        visit_generic_args(args, v);
+        try_visit!(v.visit_generic_args(args, cx));
     }
-    visit_assoc_item_constraint_kind(binding, v);
+    v.visit_assoc_item_constraint_kind(binding, cx)
 }
 
-fn visit_assoc_item_constraint_kind(binding: &AssocItemConstraintKind, v: &mut impl Visitor) {
+pub fn walk_assoc_item_constraint_kind<V: Visitor + ?Sized>(
+    v: &mut V,
+    binding: &AssocItemConstraintKind,
+    cx: RefContext,
+) -> ControlFlow<()> {
     match binding {
-        AssocItemConstraintKind::Equality(term) => visit_term(term, v),
+        AssocItemConstraintKind::Equality(term) => v.visit_term(term, cx),
         AssocItemConstraintKind::Constraint(bounds) => {
             for bound in bounds {
-                visit_generic_bound(bound, v)
+                try_visit!(v.visit_generic_bound(bound, cx));
             }
+            ControlFlow::Continue(())
         }
     }
 }
 
-fn visit_generic_arg(arg: &GenericArg, v: &mut impl Visitor) {
+pub fn walk_generic_arg<V: Visitor + ?Sized>(
+    v: &mut V,
+    arg: &GenericArg,
+    cx: RefContext,
+) -> ControlFlow<()> {
     match arg {
-        GenericArg::Lifetime(_) => {}
-        GenericArg::Type(type_) => visit_type(type_, v),
-        GenericArg::Const(_) => {}
-        GenericArg::Infer => {}
+        GenericArg::Lifetime(_) => ControlFlow::Continue(()),
+        GenericArg::Type(type_) => v.visit_type(type_, cx),
+        GenericArg::Const(_) => ControlFlow::Continue(()),
+        GenericArg::Infer => ControlFlow::Continue(()),
     }
 }
 
-fn visit_type(type_: &Type, v: &mut impl Visitor) {
+pub fn walk_type<V: Visitor + ?Sized>(v: &mut V, type_: &Type, cx: RefContext) -> ControlFlow<()> {
     match type_ {
-        Type::ResolvedPath(path) => visit_path(path, v),
-        Type::DynTrait(dyn_trait) => visit_dyn_trait(dyn_trait, v),
-        Type::Generic(_) => {}
-        Type::Primitive(_) => {}
-        Type::FunctionPointer(fn_pointer) => visit_function_pointer(fn_pointer, v),
+        Type::ResolvedPath(path) => v.visit_path(path, cx),
+        Type::DynTrait(dyn_trait) => v.visit_dyn_trait(dyn_trait),
+        Type::Generic(_) => ControlFlow::Continue(()),
+        Type::Primitive(_) => ControlFlow::Continue(()),
+        Type::FunctionPointer(fn_pointer) => v.visit_function_pointer(fn_pointer),
         Type::Tuple(types) => {
             for type_ in types {
-                visit_type(type_, v);
+                try_visit!(v.visit_type(type_, cx));
             }
+            ControlFlow::Continue(())
         }
-        Type::Slice(type_) => visit_type(type_, v),
-        Type::Array { type_, len: _ } => visit_type(type_, v),
+        Type::Slice(type_) => v.visit_type(type_, cx),
+        Type::Array { type_, len: _ } => v.visit_type(type_, cx),
         Type::ImplTrait(bounds) => {
             for bound in bounds {
-                visit_generic_bound(bound, v);
+                try_visit!(v.visit_generic_bound(bound, RefContext::ImplTrait));
             }
+            ControlFlow::Continue(())
         }
-        Type::Infer => {}
+        Type::Infer => ControlFlow::Continue(()),
         Type::RawPointer {
             is_mutable: _,
             type_,
-        } => visit_type(type_, v),
+        } => v.visit_type(type_, cx),
         Type::BorrowedRef {
             is_mutable: _,
             lifetime: _,
             type_,
-        } => visit_type(type_, v),
+        } => v.visit_type(type_, cx),
         Type::QualifiedPath {
             name: _,
             args,
             self_type,
             trait_,
         } => {
-            if let Some(args) = args {
-                visit_generic_args(args, v);
-            }
-            visit_type(self_type, v);
+            try_visit!(v.visit_generic_args(args, cx));
+            try_visit!(v.visit_type(self_type, cx));
             if let Some(trait_) = trait_ {
-                visit_path(trait_, v);
+                try_visit!(v.visit_path(trait_, cx));
             }
+            ControlFlow::Continue(())
         }
-        Type::Pat { type_, .. } => {
-            visit_type(type_, v);
-        }
+        Type::Pat { type_, .. } => v.visit_type(type_, cx),
     }
 }
 
-fn visit_function_pointer(fn_pointer: &FunctionPointer, v: &mut impl Visitor) {
+pub fn walk_function_pointer<V: Visitor + ?Sized>(
+    v: &mut V,
+    fn_pointer: &FunctionPointer,
+) -> ControlFlow<()> {
     let FunctionPointer {
         sig,
         generic_params,
         header: _,
     } = fn_pointer;
-    visit_fn_sig(sig, v);
+    try_visit!(v.visit_fn_sig(sig));
     for generic_param in generic_params {
-        visit_generic_param_def(generic_param, v);
+        try_visit!(v.visit_generic_param_def(generic_param));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_dyn_trait(dyn_trait: &DynTrait, v: &mut impl Visitor) {
+pub fn walk_dyn_trait<V: Visitor + ?Sized>(v: &mut V, dyn_trait: &DynTrait) -> ControlFlow<()> {
     let DynTrait {
         traits,
         lifetime: _,
     } = dyn_trait;
     for trait_ in traits {
-        visit_poly_trait(trait_, v);
+        try_visit!(v.visit_poly_trait(trait_));
     }
+    ControlFlow::Continue(())
 }
 
-fn visit_poly_trait(trait_: &PolyTrait, v: &mut impl Visitor) {
+pub fn walk_poly_trait<V: Visitor + ?Sized>(v: &mut V, trait_: &PolyTrait) -> ControlFlow<()> {
     let PolyTrait {
         trait_,
         generic_params,
     } = trait_;
-    visit_path(trait_, v);
+    try_visit!(v.visit_path(trait_, RefContext::DynTraitObject));
     for generic_param in generic_params {
-        visit_generic_param_def(generic_param, v);
+        try_visit!(v.visit_generic_param_def(generic_param));
+    }
+    ControlFlow::Continue(())
+}
+
+/// Walks a whole [`Crate`], resolving `Id`s that `walk_item` can't see
+/// through on its own.
+///
+/// `rustdoc_types` stores a trait's methods, an impl's members, and an
+/// enum's variants as `Id`s into the crate's `index` rather than inline
+/// `Item`s. Plain [`walk_item`] stops at the reference; `IndexWalker` looks
+/// each one up, checks that it's genuinely public API, and feeds it back
+/// through [`Visitor::visit_item`], guarding against revisiting the same
+/// `Id` (re-export cycles, a method inherited through multiple impls, ...).
+pub struct IndexWalker<'a> {
+    krate: &'a Crate,
+    visited: HashSet<Id>,
+}
+
+impl<'a> IndexWalker<'a> {
+    pub fn new(krate: &'a Crate) -> Self {
+        Self {
+            krate,
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Visits `item`, then descends into the `Id`s it contains: a trait's
+    /// items, an impl's items and provided methods, an enum's variants, the
+    /// fields of a struct or variant, a module's items, and the target of a
+    /// `Use`. A union's fields and the `impls`/`implementations` lists of
+    /// types and traits are not followed.
+    pub fn walk_item(&mut self, item: &Item, v: &mut impl Visitor) -> ControlFlow<()> {
+        try_visit!(v.visit_item(item));
+        match &item.inner {
+            ItemEnum::Trait(trait_) => {
+                // associated items of a public trait are public API even
+                // without an explicit `pub` (rustdoc leaves them `Default`)
+                for id in &trait_.items {
+                    try_visit!(self.visit_id(id, true, v));
+                }
+            }
+            ItemEnum::Impl(impl_) => {
+                // members of a trait impl inherit the trait's visibility like
+                // trait items do; an inherent impl's members need their own
+                // explicit `pub` to be public API
+                let default_is_public = impl_.trait_.is_some();
+                for id in &impl_.items {
+                    try_visit!(self.visit_id(id, default_is_public, v));
+                }
+                try_visit!(self.walk_provided_trait_methods(impl_, v));
+            }
+            ItemEnum::Enum(enum_) => {
+                // variants of a public enum are public API even without an
+                // explicit `pub` (rustdoc leaves them `Default`)
+                for id in &enum_.variants {
+                    try_visit!(self.visit_id(id, true, v));
+                }
+            }
+            ItemEnum::Struct(struct_) => {
+                try_visit!(self.walk_struct_fields(&struct_.kind, v));
+            }
+            ItemEnum::Variant(variant) => {
+                try_visit!(self.walk_variant_fields(variant, v));
+            }
+            ItemEnum::Use(use_) => {
+                try_visit!(self.walk_use(use_, v));
+            }
+            ItemEnum::Module(module) => {
+                // a module's items need their own explicit `pub` to be public API
+                for id in &module.items {
+                    try_visit!(self.visit_id(id, false, v));
+                }
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Follows a `pub use` (or glob `pub use ... ::*`) to the item(s) it
+    /// re-exports and feeds them back through [`IndexWalker::walk_item`], so
+    /// e.g. `pub use private_dep::SomeType;` is inspected the same as if
+    /// `SomeType` had been declared in this crate directly.
+    fn walk_use(&mut self, use_: &Use, v: &mut impl Visitor) -> ControlFlow<()> {
+        let Some(id) = use_.id else {
+            // `Use::id` is `None` specifically for a re-export of a primitive
+            // (`pub use i32 as my_i32;`), which has no `Id` of its own. The raw
+            // `Use` (and its `source` path) was already reported through
+            // `Visitor::visit_use` above, which is as far as we can get.
+            return ControlFlow::Continue(());
+        };
+        if !use_.is_glob {
+            // an ordinary item needs its own explicit `pub` to count as public API
+            return self.visit_id(&id, false, v);
+        }
+        let Some(target) = self.krate.index.get(&id) else {
+            // `id` is `Some` but missing from `index`: a re-export of an item
+            // from another crate, which we have no way to look up without
+            // fetching that crate's own rustdoc JSON. Same as the primitive
+            // case above, the raw `Use` is as far as we can get.
+            return ControlFlow::Continue(());
+        };
+        let ItemEnum::Module(module) = &target.inner else {
+            return ControlFlow::Continue(());
+        };
+        for child in &module.items {
+            try_visit!(self.visit_id(child, false, v));
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// `Impl::provided_trait_methods` holds the *names* of default trait
+    /// methods this impl didn't override, not `Id`s -- look the trait up by
+    /// `Impl::trait_`'s `Id` and resolve the matching items from there.
+    fn walk_provided_trait_methods(
+        &mut self,
+        impl_: &Impl,
+        v: &mut impl Visitor,
+    ) -> ControlFlow<()> {
+        if impl_.provided_trait_methods.is_empty() {
+            return ControlFlow::Continue(());
+        }
+        let Some(trait_path) = &impl_.trait_ else {
+            return ControlFlow::Continue(());
+        };
+        let Some(ItemEnum::Trait(trait_)) = self.krate.index.get(&trait_path.id).map(|i| &i.inner)
+        else {
+            return ControlFlow::Continue(());
+        };
+        for id in &trait_.items {
+            let Some(method) = self.krate.index.get(id) else {
+                continue;
+            };
+            if impl_
+                .provided_trait_methods
+                .iter()
+                .any(|name| method.name.as_deref() == Some(name.as_str()))
+            {
+                // resolved via the trait's own item list, so the same
+                // "public trait item" exception applies
+                try_visit!(self.visit_id(id, true, v));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// `StructKind::{Tuple, Plain}` hold `Id`s that point to `ItemEnum::StructField`,
+    /// the same shape as `VariantKind` (see [`IndexWalker::walk_variant_fields`]).
+    /// Unlike enum variants, struct fields need their own explicit `pub` to be
+    /// public API.
+    fn walk_struct_fields(&mut self, kind: &StructKind, v: &mut impl Visitor) -> ControlFlow<()> {
+        match kind {
+            StructKind::Unit => {}
+            StructKind::Tuple(fields) => {
+                for field in fields.iter().flatten() {
+                    try_visit!(self.visit_id(field, false, v));
+                }
+            }
+            StructKind::Plain {
+                fields,
+                has_stripped_fields: _,
+            } => {
+                for field in fields {
+                    try_visit!(self.visit_id(field, false, v));
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn walk_variant_fields(&mut self, variant: &Variant, v: &mut impl Visitor) -> ControlFlow<()> {
+        match &variant.kind {
+            VariantKind::Plain => {}
+            VariantKind::Tuple(fields) => {
+                for field in fields.iter().flatten() {
+                    // a variant's own fields have no individual `pub` syntax;
+                    // they're as public as the variant they belong to
+                    try_visit!(self.visit_id(field, true, v));
+                }
+            }
+            VariantKind::Struct {
+                fields,
+                has_stripped_fields: _,
+            } => {
+                for field in fields {
+                    try_visit!(self.visit_id(field, true, v));
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Looks `id` up in the crate index and, if it's genuinely public API and
+    /// hasn't been visited yet, descends into it.
+    ///
+    /// `default_is_public` says whether `Visibility::Default` should count as
+    /// public here: true for the few items `rustdoc_types::Visibility::Default`
+    /// documents as exceptions to "private by default" (associated items of a
+    /// public trait, variants of a public enum, and anything inheriting their
+    /// visibility), false everywhere else (struct fields, inherent impl
+    /// members, ordinary re-exported items), which need an explicit `pub`.
+    fn visit_id(
+        &mut self,
+        id: &Id,
+        default_is_public: bool,
+        v: &mut impl Visitor,
+    ) -> ControlFlow<()> {
+        if !self.visited.insert(*id) {
+            return ControlFlow::Continue(());
+        }
+        let Some(item) = self.krate.index.get(id) else {
+            return ControlFlow::Continue(());
+        };
+        if !is_public_api(item, default_is_public) {
+            return ControlFlow::Continue(());
+        }
+        self.walk_item(item, v)
+    }
+}
+
+/// An item counts as public API if it isn't `#[doc(hidden)]` and rustdoc
+/// considers it visible. `Visibility::Default` means "private" except for the
+/// handful of cases `default_is_public` identifies (see [`IndexWalker::visit_id`]);
+/// everywhere else (struct fields, inherent impl members, module items,
+/// re-exported items) an explicit `pub` is needed to count.
+fn is_public_api(item: &Item, default_is_public: bool) -> bool {
+    let visible = match item.visibility {
+        Visibility::Public => true,
+        Visibility::Default => default_is_public,
+        Visibility::Crate | Visibility::Restricted { .. } => false,
+    };
+    let hidden = item.attrs.iter().any(|attr| attr.contains("doc(hidden)"));
+    visible && !hidden
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rustdoc_types::Module;
+
+    use super::*;
+
+    fn item(id: u32, name: &str, visibility: Visibility, inner: ItemEnum) -> Item {
+        Item {
+            id: Id(id),
+            crate_id: 0,
+            name: Some(name.to_owned()),
+            span: None,
+            visibility,
+            docs: None,
+            links: HashMap::new(),
+            attrs: Vec::new(),
+            deprecation: None,
+            inner,
+        }
+    }
+
+    fn resolved_path(name: &str, id: u32) -> Type {
+        Type::ResolvedPath(Path {
+            path: name.to_owned(),
+            id: Id(id),
+            args: None,
+        })
+    }
+
+    fn unit_struct(id: u32, name: &str, visibility: Visibility) -> Item {
+        item(
+            id,
+            name,
+            visibility,
+            ItemEnum::Struct(Struct {
+                kind: StructKind::Unit,
+                generics: Generics {
+                    params: Vec::new(),
+                    where_predicates: Vec::new(),
+                },
+                impls: Vec::new(),
+            }),
+        )
+    }
+
+    fn module(id: u32, name: &str, items: Vec<Id>) -> Item {
+        item(
+            id,
+            name,
+            Visibility::Public,
+            ItemEnum::Module(Module {
+                is_crate: id == 0,
+                items,
+                is_stripped: false,
+            }),
+        )
+    }
+
+    fn use_item(id: u32, name: &str, target: Option<Id>, is_glob: bool) -> Item {
+        item(
+            id,
+            name,
+            Visibility::Public,
+            ItemEnum::Use(Use {
+                source: name.to_owned(),
+                name: name.to_owned(),
+                id: target,
+                is_glob,
+            }),
+        )
+    }
+
+    /// A `Crate` with one public module containing `pub struct Leaky { pub leaked:
+    /// PrivateDepType, hidden: PrivateDepType }`, where `PrivateDepType` stands in
+    /// for a type from a private dependency.
+    fn leaky_struct_crate() -> Crate {
+        let root = Id(0);
+        let struct_id = Id(1);
+        let leaked_field_id = Id(2);
+        let hidden_field_id = Id(3);
+
+        let mut index = HashMap::new();
+        index.insert(
+            root,
+            item(
+                0,
+                "root",
+                Visibility::Public,
+                ItemEnum::Module(Module {
+                    is_crate: true,
+                    items: vec![struct_id],
+                    is_stripped: false,
+                }),
+            ),
+        );
+        index.insert(
+            struct_id,
+            item(
+                1,
+                "Leaky",
+                Visibility::Public,
+                ItemEnum::Struct(Struct {
+                    kind: StructKind::Plain {
+                        fields: vec![leaked_field_id, hidden_field_id],
+                        has_stripped_fields: false,
+                    },
+                    generics: Generics {
+                        params: Vec::new(),
+                        where_predicates: Vec::new(),
+                    },
+                    impls: Vec::new(),
+                }),
+            ),
+        );
+        index.insert(
+            leaked_field_id,
+            item(
+                2,
+                "leaked",
+                Visibility::Public,
+                ItemEnum::StructField(resolved_path("PrivateDepType", 100)),
+            ),
+        );
+        index.insert(
+            hidden_field_id,
+            item(
+                3,
+                "hidden",
+                // no explicit `pub`; struct fields aren't one of the
+                // `Visibility::Default`-is-public exceptions
+                Visibility::Default,
+                ItemEnum::StructField(resolved_path("PrivateDepType", 100)),
+            ),
+        );
+
+        Crate {
+            root,
+            crate_version: None,
+            includes_private: false,
+            index,
+            paths: HashMap::new(),
+            external_crates: HashMap::new(),
+            format_version: 34,
+        }
+    }
+
+    /// Records every path this `Visitor` is asked to visit, alongside the
+    /// [`RefContext`] it was visited under.
+    #[derive(Default)]
+    struct PathCollector {
+        paths: Vec<(String, RefContext)>,
+    }
+
+    impl Visitor for PathCollector {
+        fn visit_path(&mut self, path: &Path, cx: RefContext) -> ControlFlow<()> {
+            self.paths.push((path.path.clone(), cx));
+            ControlFlow::Continue(())
+        }
+    }
+
+    #[test]
+    fn struct_field_leak_is_found_but_private_field_is_not() {
+        let krate = leaky_struct_crate();
+        let root = krate.index.get(&krate.root).unwrap().clone();
+        let mut walker = IndexWalker::new(&krate);
+        let mut collector = PathCollector::default();
+
+        assert!(matches!(
+            walker.walk_item(&root, &mut collector),
+            ControlFlow::Continue(())
+        ));
+        assert_eq!(
+            collector.paths,
+            vec![("PrivateDepType".to_owned(), RefContext::StructField)],
+        );
+    }
+
+    /// Records the name of every item this `Visitor` is asked to visit, in
+    /// visitation order, while still descending as normal.
+    #[derive(Default)]
+    struct ItemNameCollector {
+        names: Vec<String>,
+    }
+
+    impl Visitor for ItemNameCollector {
+        fn visit_item(&mut self, item: &Item) -> ControlFlow<()> {
+            if let Some(name) = &item.name {
+                self.names.push(name.clone());
+            }
+            walk_item(self, item)
+        }
+    }
+
+    fn walk_from_root(krate: &Crate) -> Vec<String> {
+        let root = krate.index.get(&krate.root).unwrap().clone();
+        let mut walker = IndexWalker::new(krate);
+        let mut collector = ItemNameCollector::default();
+        assert!(matches!(
+            walker.walk_item(&root, &mut collector),
+            ControlFlow::Continue(())
+        ));
+        collector.names
+    }
+
+    #[test]
+    fn ordinary_reexport_is_followed() {
+        // pub use private_dep::Thing;
+        let root = Id(0);
+        let use_id = Id(1);
+        let target_id = Id(2);
+
+        let mut index = HashMap::new();
+        index.insert(root, module(0, "root", vec![use_id]));
+        index.insert(use_id, use_item(1, "Thing", Some(target_id), false));
+        index.insert(target_id, unit_struct(2, "Thing", Visibility::Public));
+
+        let krate = Crate {
+            root,
+            crate_version: None,
+            includes_private: false,
+            index,
+            paths: HashMap::new(),
+            external_crates: HashMap::new(),
+            format_version: 34,
+        };
+
+        assert_eq!(walk_from_root(&krate), vec!["root", "Thing", "Thing"]);
+    }
+
+    #[test]
+    fn glob_reexport_is_expanded() {
+        // pub use private_dep::*;
+        let root = Id(0);
+        let use_id = Id(1);
+        let reexported_module_id = Id(2);
+        let child_id = Id(3);
+
+        let mut index = HashMap::new();
+        index.insert(root, module(0, "root", vec![use_id]));
+        index.insert(
+            use_id,
+            use_item(1, "private_dep", Some(reexported_module_id), true),
+        );
+        index.insert(
+            reexported_module_id,
+            module(2, "private_dep", vec![child_id]),
+        );
+        index.insert(child_id, unit_struct(3, "GlobThing", Visibility::Public));
+
+        let krate = Crate {
+            root,
+            crate_version: None,
+            includes_private: false,
+            index,
+            paths: HashMap::new(),
+            external_crates: HashMap::new(),
+            format_version: 34,
+        };
+
+        assert_eq!(
+            walk_from_root(&krate),
+            vec!["root", "private_dep", "GlobThing"],
+        );
+    }
+
+    #[test]
+    fn primitive_reexport_has_no_id_to_follow() {
+        // pub use i32 as my_i32;
+        let root = Id(0);
+        let use_id = Id(1);
+
+        let mut index = HashMap::new();
+        index.insert(root, module(0, "root", vec![use_id]));
+        index.insert(use_id, use_item(1, "my_i32", None, false));
+
+        let krate = Crate {
+            root,
+            crate_version: None,
+            includes_private: false,
+            index,
+            paths: HashMap::new(),
+            external_crates: HashMap::new(),
+            format_version: 34,
+        };
+
+        assert_eq!(walk_from_root(&krate), vec!["root", "my_i32"]);
+    }
+
+    #[test]
+    fn reexport_cycle_terminates() {
+        // mod a { pub use b::*; } mod b { pub use a::*; }, reached from the root
+        let root = Id(0);
+        let use_a_id = Id(1);
+        let mod_b_id = Id(2);
+        let use_b_id = Id(3);
+
+        let mut index = HashMap::new();
+        index.insert(root, module(0, "root", vec![use_a_id]));
+        index.insert(use_a_id, use_item(1, "b_reexport", Some(mod_b_id), true));
+        index.insert(mod_b_id, module(2, "b", vec![use_b_id]));
+        // glob re-export back to the root module, closing the cycle
+        index.insert(use_b_id, use_item(3, "root_reexport", Some(root), true));
+
+        let krate = Crate {
+            root,
+            crate_version: None,
+            includes_private: false,
+            index,
+            paths: HashMap::new(),
+            external_crates: HashMap::new(),
+            format_version: 34,
+        };
+
+        // terminates instead of looping forever, and doesn't revisit `use_a_id`
+        assert_eq!(
+            walk_from_root(&krate),
+            vec!["root", "b_reexport", "root_reexport"],
+        );
     }
 }